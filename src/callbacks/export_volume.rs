@@ -0,0 +1,181 @@
+use std::path::{Path, PathBuf};
+
+use bollard::{
+    container::{Config as ContainerConfig, CreateContainerOptions, RemoveContainerOptions},
+    models::{HostConfig, Mount, MountTypeEnum},
+    Docker,
+};
+use color_eyre::eyre::{Context, ContextCompat, Result};
+use futures::StreamExt;
+
+use crate::{docker::volume::DockerVolume, traits::Callback};
+
+const HELPER_IMAGE: &str = "alpine";
+const VOLUME_MOUNT: &str = "/ducker-volume";
+const DEST_MOUNT: &str = "/ducker-dest";
+
+#[derive(Debug)]
+pub struct ExportVolume {
+    docker: Docker,
+    volume: DockerVolume,
+    destination: String,
+}
+
+impl ExportVolume {
+    #[must_use]
+    pub fn new(docker: Docker, volume: DockerVolume, destination: String) -> Self {
+        Self {
+            docker,
+            volume,
+            destination,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Callback for ExportVolume {
+    async fn call(&mut self) -> Result<()> {
+        let (dest_dir, dest_file) = split_destination(&self.destination)?;
+
+        let name = format!("ducker-export-{}-{}", self.volume.name, std::process::id());
+
+        let options = CreateContainerOptions {
+            name: name.clone(),
+            platform: None,
+        };
+
+        let config = ContainerConfig {
+            image: Some(HELPER_IMAGE.to_string()),
+            cmd: Some(vec![
+                "tar".to_string(),
+                "cf".to_string(),
+                format!("{DEST_MOUNT}/{dest_file}"),
+                "-C".to_string(),
+                VOLUME_MOUNT.to_string(),
+                ".".to_string(),
+            ]),
+            host_config: Some(HostConfig {
+                mounts: Some(vec![
+                    Mount {
+                        target: Some(VOLUME_MOUNT.to_string()),
+                        source: Some(self.volume.name.clone()),
+                        typ: Some(MountTypeEnum::VOLUME),
+                        read_only: Some(true),
+                        ..Default::default()
+                    },
+                    Mount {
+                        target: Some(DEST_MOUNT.to_string()),
+                        source: Some(dest_dir.to_string_lossy().to_string()),
+                        typ: Some(MountTypeEnum::BIND),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let container = self
+            .docker
+            .create_container(Some(options), config)
+            .await
+            .context("unable to create volume export helper container")?;
+
+        // However the export goes, the helper container must not be left
+        // behind: remove it on both the success and failure paths before
+        // propagating the original result.
+        let result = self.run_export(&container.id).await;
+
+        self.docker
+            .remove_container(
+                &container.id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .ok();
+
+        result
+    }
+}
+
+impl ExportVolume {
+    async fn run_export(&self, container_id: &str) -> Result<()> {
+        self.docker
+            .start_container::<String>(container_id, None)
+            .await
+            .context("unable to start volume export helper container")?;
+
+        let mut waits = self.docker.wait_container::<String>(container_id, None);
+        while let Some(result) = waits.next().await {
+            result.context("volume export helper container exited with an error")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits an export destination into the (canonicalized, absolute) directory
+/// it lives in and its file name, so the directory can be used as a Docker
+/// bind-mount source — which the daemon requires to be an absolute host
+/// path, not one relative to ducker's own working directory.
+fn split_destination(destination: &str) -> Result<(PathBuf, String)> {
+    let dest_path = Path::new(destination);
+
+    let dest_file = dest_path
+        .file_name()
+        .context("destination path has no file name")?
+        .to_string_lossy()
+        .to_string();
+
+    let dest_dir = match dest_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => std::env::current_dir().context("unable to determine current directory")?,
+    };
+
+    let dest_dir = std::fs::canonicalize(&dest_dir)
+        .with_context(|| format!("destination directory {} does not exist", dest_dir.display()))?;
+
+    Ok((dest_dir, dest_file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_relative_path_in_current_directory() {
+        let (dir, file) = split_destination("myvolume.tar").unwrap();
+        assert_eq!(dir, std::env::current_dir().unwrap());
+        assert_eq!(file, "myvolume.tar");
+    }
+
+    #[test]
+    fn splits_explicit_relative_directory() {
+        let (dir, file) = split_destination("./myvolume.tar").unwrap();
+        assert_eq!(dir, std::env::current_dir().unwrap());
+        assert_eq!(file, "myvolume.tar");
+    }
+
+    #[test]
+    fn splits_absolute_path() {
+        let dir = std::env::current_dir().unwrap();
+        let destination = dir.join("myvolume.tar");
+
+        let (split_dir, file) = split_destination(destination.to_str().unwrap()).unwrap();
+        assert_eq!(split_dir, dir);
+        assert_eq!(file, "myvolume.tar");
+    }
+
+    #[test]
+    fn rejects_destination_with_no_file_name() {
+        assert!(split_destination("/").is_err());
+    }
+
+    #[test]
+    fn rejects_directory_that_does_not_exist() {
+        assert!(split_destination("/this/path/does/not/exist-ducker-test/out.tar").is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use bollard::Docker;
+use color_eyre::eyre::{Context, Result};
+
+use crate::traits::Callback;
+
+#[derive(Debug)]
+pub struct PruneVolumes {
+    docker: Docker,
+}
+
+impl PruneVolumes {
+    #[must_use]
+    pub fn new(docker: Docker) -> Self {
+        Self { docker }
+    }
+}
+
+#[async_trait::async_trait]
+impl Callback for PruneVolumes {
+    async fn call(&mut self) -> Result<()> {
+        self.docker
+            .prune_volumes::<String>(None)
+            .await
+            .context("unable to prune unused volumes")?;
+
+        Ok(())
+    }
+}
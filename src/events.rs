@@ -0,0 +1,54 @@
+pub mod message;
+
+use std::fmt;
+
+use crate::context::AppContext;
+
+/// A normalised key event, independent of the terminal backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Esc,
+    Backspace,
+    Char(char),
+    Ctrl(char),
+    Alt(char),
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Up => write!(f, "↑"),
+            Key::Down => write!(f, "↓"),
+            Key::Left => write!(f, "←"),
+            Key::Right => write!(f, "→"),
+            Key::Enter => write!(f, "enter"),
+            Key::Esc => write!(f, "esc"),
+            Key::Backspace => write!(f, "backspace"),
+            Key::Char(c) => write!(f, "{c}"),
+            Key::Ctrl(c) => write!(f, "ctrl-{c}"),
+            Key::Alt(c) => write!(f, "alt-{c}"),
+        }
+    }
+}
+
+/// A message flowing through the app's event loop: either a raw key to hand
+/// to the active page, or a request to transition to a different page.
+#[derive(Debug, Clone)]
+pub enum Message<K, T> {
+    Key(K),
+    Transition(T),
+}
+
+/// Requests to navigate to a different page, carrying whatever context the
+/// destination page needs to initialise itself.
+#[derive(Debug, Clone)]
+pub enum Transition {
+    ToVolumePage(AppContext),
+    ToDescribeContainerPage(AppContext),
+    ToBrowseVolumePage(AppContext),
+}
@@ -0,0 +1,7 @@
+/// Whether a page or component consumed a key event, or left it unhandled
+/// so an enclosing layer can try it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageResponse {
+    Consumed,
+    NotConsumed,
+}
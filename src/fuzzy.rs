@@ -0,0 +1,145 @@
+//! A small self-contained fuzzy matcher for incremental filter/search UIs.
+//!
+//! Matching is subsequence-based (every query character must appear in order
+//! in the candidate, but not necessarily contiguously) with a score that
+//! rewards consecutive runs and word-boundary starts, so results can be
+//! ranked rather than just included/excluded.
+
+const CONSECUTIVE_BONUS: i64 = 15;
+const WORD_BOUNDARY_BONUS: i64 = 30;
+const GAP_PENALTY: i64 = 2;
+const LATE_START_PENALTY: i64 = 3;
+
+/// Score `candidate` against `query` using case-insensitive subsequence matching.
+///
+/// Returns `None` if `query` is not a subsequence of `candidate`. Otherwise
+/// returns a score where a higher value means a better match.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score: i64 = 0;
+    let mut query_idx = 0;
+    let mut first_match: Option<usize> = None;
+    let mut last_match: Option<usize> = None;
+    let mut run: i64 = 0;
+
+    for (idx, &c) in candidate_lower.iter().enumerate() {
+        if query_idx >= query.len() {
+            break;
+        }
+        if c != query[query_idx] {
+            continue;
+        }
+
+        first_match.get_or_insert(idx);
+
+        match last_match {
+            Some(last) if idx == last + 1 => {
+                run += 1;
+                score += CONSECUTIVE_BONUS * run;
+            }
+            Some(last) => {
+                run = 0;
+                score -= GAP_PENALTY * (idx - last - 1) as i64;
+            }
+            None => {}
+        }
+
+        if is_word_boundary(&candidate_chars, idx) {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        last_match = Some(idx);
+        query_idx += 1;
+    }
+
+    if query_idx < query.len() {
+        return None;
+    }
+
+    if let Some(first) = first_match {
+        score -= LATE_START_PENALTY * first as i64;
+    }
+
+    Some(score)
+}
+
+fn is_word_boundary(chars: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+
+    let prev = chars[idx - 1];
+    if matches!(prev, '-' | '_' | '/') {
+        return true;
+    }
+
+    prev.is_lowercase() && chars[idx].is_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_anything_with_zero_score() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn non_subsequence_does_not_match() {
+        assert_eq!(fuzzy_score("xyz", "hello"), None);
+    }
+
+    #[test]
+    fn out_of_order_characters_do_not_match() {
+        assert_eq!(fuzzy_score("ba", "ab"), None);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        assert!(fuzzy_score("VOL", "my-volume").is_some());
+        assert_eq!(fuzzy_score("VOL", "my-volume"), fuzzy_score("vol", "my-volume"));
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher_than_gapped_ones() {
+        let consecutive = fuzzy_score("ab", "ab").unwrap();
+        let gapped = fuzzy_score("ab", "axb").unwrap();
+        assert!(consecutive > gapped, "{consecutive} should be > {gapped}");
+    }
+
+    #[test]
+    fn wider_gaps_score_lower() {
+        let small_gap = fuzzy_score("ab", "axb").unwrap();
+        let big_gap = fuzzy_score("ab", "axxxb").unwrap();
+        assert!(small_gap > big_gap, "{small_gap} should be > {big_gap}");
+    }
+
+    #[test]
+    fn separator_word_boundary_scores_higher_than_mid_word() {
+        let at_boundary = fuzzy_score("b", "foo-bar").unwrap();
+        let mid_word = fuzzy_score("b", "foobar").unwrap();
+        assert!(at_boundary > mid_word, "{at_boundary} should be > {mid_word}");
+    }
+
+    #[test]
+    fn camel_case_word_boundary_scores_higher_than_mid_word() {
+        let at_boundary = fuzzy_score("b", "fooBar").unwrap();
+        let mid_word = fuzzy_score("b", "foobar").unwrap();
+        assert!(at_boundary > mid_word, "{at_boundary} should be > {mid_word}");
+    }
+
+    #[test]
+    fn earlier_first_match_scores_higher() {
+        let early = fuzzy_score("z", "zoo").unwrap();
+        let late = fuzzy_score("z", "buzz").unwrap();
+        assert!(early > late, "{early} should be > {late}");
+    }
+}
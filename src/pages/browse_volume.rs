@@ -0,0 +1,357 @@
+use bollard::{
+    container::{Config as ContainerConfig, CreateContainerOptions, RemoveContainerOptions},
+    exec::{CreateExecOptions, StartExecResults},
+    models::{HostConfig, Mount, MountTypeEnum},
+    Docker,
+};
+use color_eyre::eyre::{bail, Context, ContextCompat, Result};
+use futures::StreamExt;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    prelude::*,
+    style::Style,
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::Sender;
+
+use crate::{
+    components::help::{PageHelp, PageHelpBuilder},
+    config::Config,
+    context::AppContext,
+    docker::volume::DockerVolume,
+    events::{message::MessageResponse, Key, Message, Transition},
+    traits::{Close, Component, Page},
+};
+
+const NAME: &str = "Browse Volume";
+
+/// Image used for the short-lived helper container that mounts the volume
+/// read-only so its contents can be listed/previewed through exec calls.
+const HELPER_IMAGE: &str = "alpine";
+const MOUNT_PATH: &str = "/ducker-volume";
+const PREVIEW_BYTES: usize = 4096;
+
+const UP_KEY: Key = Key::Up;
+const DOWN_KEY: Key = Key::Down;
+const J_KEY: Key = Key::Char('j');
+const K_KEY: Key = Key::Char('k');
+const ENTER_KEY: Key = Key::Enter;
+const RIGHT_KEY: Key = Key::Right;
+const LEFT_KEY: Key = Key::Left;
+const BACKSPACE_KEY: Key = Key::Backspace;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    name: String,
+    is_dir: bool,
+}
+
+#[derive(Debug)]
+pub struct BrowseVolume {
+    pub name: String,
+    tx: Sender<Message<Key, Transition>>,
+    page_help: Arc<Mutex<PageHelp>>,
+    docker: Docker,
+    volume: Option<DockerVolume>,
+    helper_container_id: Option<String>,
+    path_stack: Vec<String>,
+    entries: Vec<Entry>,
+    list_state: ListState,
+    preview: String,
+}
+
+#[async_trait::async_trait]
+impl Page for BrowseVolume {
+    async fn update(&mut self, message: Key) -> Result<MessageResponse> {
+        let result = match message {
+            UP_KEY | K_KEY => {
+                self.decrement_list();
+                self.update_preview().await?;
+                MessageResponse::Consumed
+            }
+            DOWN_KEY | J_KEY => {
+                self.increment_list();
+                self.update_preview().await?;
+                MessageResponse::Consumed
+            }
+            ENTER_KEY | RIGHT_KEY => {
+                self.enter_selected().await?;
+                MessageResponse::Consumed
+            }
+            LEFT_KEY | BACKSPACE_KEY => {
+                self.leave_directory().await?;
+                MessageResponse::Consumed
+            }
+            _ => MessageResponse::NotConsumed,
+        };
+        Ok(result)
+    }
+
+    async fn initialise(&mut self, cx: AppContext) -> Result<()> {
+        let volume = cx
+            .docker_volume
+            .context("no volume was given to browse")?;
+
+        let container_id = spawn_helper_container(&self.docker, &volume.name).await?;
+        self.helper_container_id = Some(container_id);
+        self.volume = Some(volume);
+        self.path_stack = vec![];
+
+        self.refresh_entries().await?;
+
+        Ok(())
+    }
+
+    fn get_help(&self) -> Arc<Mutex<PageHelp>> {
+        self.page_help.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Close for BrowseVolume {
+    async fn close(&mut self) -> Result<()> {
+        if let Some(container_id) = self.helper_container_id.take() {
+            self.docker
+                .remove_container(
+                    &container_id,
+                    Some(RemoveContainerOptions {
+                        force: true,
+                        ..Default::default()
+                    }),
+                )
+                .await
+                .ok();
+        }
+        Ok(())
+    }
+}
+
+impl BrowseVolume {
+    #[must_use]
+    pub fn new(docker: Docker, tx: Sender<Message<Key, Transition>>, config: Arc<Config>) -> Self {
+        let page_help = PageHelpBuilder::new(NAME.to_string(), config.clone())
+            .add_input(format!("{ENTER_KEY}"), "open".to_string())
+            .add_input(format!("{LEFT_KEY}"), "back".to_string())
+            .build();
+
+        Self {
+            name: String::from(NAME),
+            tx,
+            page_help: Arc::new(Mutex::new(page_help)),
+            docker,
+            volume: None,
+            helper_container_id: None,
+            path_stack: vec![],
+            entries: vec![],
+            list_state: ListState::default(),
+            preview: String::new(),
+        }
+    }
+
+    fn current_path(&self) -> String {
+        self.path_stack.join("/")
+    }
+
+    async fn refresh_entries(&mut self) -> Result<()> {
+        let container_id = self
+            .helper_container_id
+            .clone()
+            .context("no helper container available to browse this volume")?;
+
+        self.entries = list_dir(&self.docker, &container_id, &self.current_path()).await?;
+        self.list_state.select((!self.entries.is_empty()).then_some(0));
+        self.update_preview().await?;
+
+        Ok(())
+    }
+
+    async fn update_preview(&mut self) -> Result<()> {
+        let Some(entry) = self.selected_entry() else {
+            self.preview = String::new();
+            return Ok(());
+        };
+
+        if entry.is_dir {
+            self.preview = String::new();
+            return Ok(());
+        }
+
+        let container_id = self
+            .helper_container_id
+            .clone()
+            .context("no helper container available to browse this volume")?;
+        let path = format!("{}/{}", self.current_path(), entry.name);
+
+        self.preview = read_preview(&self.docker, &container_id, &path).await?;
+
+        Ok(())
+    }
+
+    async fn enter_selected(&mut self) -> Result<()> {
+        let Some(entry) = self.selected_entry() else {
+            return Ok(());
+        };
+
+        if entry.is_dir {
+            self.path_stack.push(entry.name.clone());
+            self.refresh_entries().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn leave_directory(&mut self) -> Result<()> {
+        if self.path_stack.pop().is_some() {
+            self.refresh_entries().await?;
+        }
+
+        Ok(())
+    }
+
+    fn selected_entry(&self) -> Option<Entry> {
+        self.list_state
+            .selected()
+            .and_then(|idx| self.entries.get(idx))
+            .cloned()
+    }
+
+    fn increment_list(&mut self) {
+        let current_idx = self.list_state.selected();
+        match current_idx {
+            None => self.list_state.select(Some(0)),
+            Some(current_idx) => {
+                if !self.entries.is_empty() && current_idx < self.entries.len() - 1 {
+                    self.list_state.select(Some(current_idx + 1));
+                }
+            }
+        }
+    }
+
+    fn decrement_list(&mut self) {
+        let current_idx = self.list_state.selected();
+        match current_idx {
+            None => self.list_state.select(Some(0)),
+            Some(current_idx) => {
+                if current_idx > 0 {
+                    self.list_state.select(Some(current_idx - 1));
+                }
+            }
+        }
+    }
+}
+
+impl Component for BrowseVolume {
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) {
+        let chunks =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).split(area);
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|e| ListItem::new(if e.is_dir { format!("{}/", e.name) } else { e.name.clone() }))
+            .collect();
+
+        let list = List::new(items).highlight_style(Style::new().reversed());
+        f.render_stateful_widget(list, chunks[0], &mut self.list_state);
+
+        let preview = Paragraph::new(self.preview.as_str());
+        f.render_widget(preview, chunks[1]);
+    }
+}
+
+async fn spawn_helper_container(docker: &Docker, volume_name: &str) -> Result<String> {
+    let name = format!("ducker-browse-{volume_name}-{}", std::process::id());
+
+    let options = CreateContainerOptions {
+        name: name.clone(),
+        platform: None,
+    };
+
+    let config = ContainerConfig {
+        image: Some(HELPER_IMAGE.to_string()),
+        cmd: Some(vec!["sleep".to_string(), "3600".to_string()]),
+        host_config: Some(HostConfig {
+            mounts: Some(vec![Mount {
+                target: Some(MOUNT_PATH.to_string()),
+                source: Some(volume_name.to_string()),
+                typ: Some(MountTypeEnum::VOLUME),
+                read_only: Some(true),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let container = docker
+        .create_container(Some(options), config)
+        .await
+        .context("unable to create volume browser helper container")?;
+
+    docker
+        .start_container::<String>(&container.id, None)
+        .await
+        .context("unable to start volume browser helper container")?;
+
+    Ok(container.id)
+}
+
+async fn list_dir(docker: &Docker, container_id: &str, path: &str) -> Result<Vec<Entry>> {
+    let full_path = format!("{MOUNT_PATH}/{path}");
+
+    let output = exec(docker, container_id, vec!["ls", "-Ap", &full_path]).await?;
+
+    let entries = output
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let is_dir = line.ends_with('/');
+            Entry {
+                name: line.trim_end_matches('/').to_string(),
+                is_dir,
+            }
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+async fn read_preview(docker: &Docker, container_id: &str, path: &str) -> Result<String> {
+    let full_path = format!("{MOUNT_PATH}/{path}");
+    let byte_limit = PREVIEW_BYTES.to_string();
+
+    exec(docker, container_id, vec!["head", "-c", &byte_limit, &full_path]).await
+}
+
+async fn exec(docker: &Docker, container_id: &str, cmd: Vec<&str>) -> Result<String> {
+    let created = docker
+        .create_exec(
+            container_id,
+            CreateExecOptions {
+                cmd: Some(cmd.into_iter().map(String::from).collect()),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .context("unable to create exec")?;
+
+    let started = docker
+        .start_exec(&created.id, None)
+        .await
+        .context("unable to start exec")?;
+
+    let mut output = String::new();
+    if let StartExecResults::Attached { mut output: stream, .. } = started {
+        while let Some(Ok(chunk)) = stream.next().await {
+            output.push_str(&chunk.to_string());
+        }
+    } else {
+        bail!("exec was detached instead of attached");
+    }
+
+    Ok(output)
+}
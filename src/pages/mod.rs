@@ -0,0 +1,34 @@
+pub mod browse_volume;
+pub mod volumes;
+
+use std::sync::Arc;
+
+use bollard::Docker;
+use tokio::sync::mpsc::Sender;
+
+use crate::{
+    config::Config,
+    context::AppContext,
+    events::{Key, Message, Transition},
+    pages::browse_volume::BrowseVolume,
+    traits::Page,
+};
+
+/// Constructs the page (and the context it should initialise with) that a
+/// `Transition` navigates to, for the transitions this module owns.
+///
+/// Returns `None` for any transition handled elsewhere, so callers can fall
+/// through to their own dispatch for the rest of the app's pages.
+pub fn page_for_transition(
+    transition: Transition,
+    docker: Docker,
+    tx: Sender<Message<Key, Transition>>,
+    config: Arc<Config>,
+) -> Option<(Box<dyn Page>, AppContext)> {
+    match transition {
+        Transition::ToBrowseVolumePage(cx) => {
+            Some((Box::new(BrowseVolume::new(docker, tx, config)), cx))
+        }
+        _ => None,
+    }
+}
@@ -1,4 +1,4 @@
-use bollard::Docker;
+use bollard::{models::VolumeUsageData, Docker};
 use color_eyre::eyre::{bail, Context, ContextCompat, Result};
 use futures::lock::Mutex as FutureMutex;
 use ratatui::{
@@ -12,11 +12,14 @@ use ratatui_macros::constraints;
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 use tokio::sync::mpsc::Sender;
 
 use crate::{
-    callbacks::delete_volume::DeleteVolume,
+    callbacks::{
+        delete_volume::DeleteVolume, export_volume::ExportVolume, prune_volumes::PruneVolumes,
+    },
     components::{
         boolean_modal::{BooleanModal, ModalState},
         help::{PageHelp, PageHelpBuilder},
@@ -25,6 +28,7 @@ use crate::{
     context::AppContext,
     docker::volume::DockerVolume,
     events::{message::MessageResponse, Key, Message, Transition},
+    fuzzy::fuzzy_score,
     sorting::{SortOrder, SortState, VolumeSortField},
     traits::{Close, Component, ModalComponent, Page},
     ui::{get_field_sort_order, is_field_sorted, render_column_header},
@@ -38,8 +42,11 @@ const DOWN_KEY: Key = Key::Down;
 const J_KEY: Key = Key::Char('j');
 const K_KEY: Key = Key::Char('k');
 const CTRL_D_KEY: Key = Key::Ctrl('d');
+const CTRL_E_KEY: Key = Key::Ctrl('e');
 const SHIFT_D_KEY: Key = Key::Char('D');
+const SHIFT_P_KEY: Key = Key::Char('P');
 const D_KEY: Key = Key::Char('d');
+const B_KEY: Key = Key::Char('b');
 const G_KEY: Key = Key::Char('g');
 const SHIFT_G_KEY: Key = Key::Char('G');
 const ALT_D_KEY: Key = Key::Alt('d');
@@ -48,13 +55,44 @@ const ALT_D_KEY: Key = Key::Alt('d');
 const SHIFT_N_KEY: Key = Key::Char('N');
 const SHIFT_C_KEY: Key = Key::Char('C');
 const SHIFT_M_KEY: Key = Key::Char('M');
+const SHIFT_S_KEY: Key = Key::Char('S');
+
+/// Volume usage data is only refetched via `docker system df` when the cache
+/// is older than this, since it is far more expensive than a plain list.
+const USAGE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+// Filter keys
+const SLASH_KEY: Key = Key::Char('/');
+const FILTER_EXIT_KEY: Key = Key::Esc;
+const FILTER_CONFIRM_KEY: Key = Key::Enter;
+const FILTER_BACKSPACE_KEY: Key = Key::Backspace;
+
+// Export destination input keys
+const EXPORT_EXIT_KEY: Key = Key::Esc;
+const EXPORT_CONFIRM_KEY: Key = Key::Enter;
+const EXPORT_BACKSPACE_KEY: Key = Key::Backspace;
+
+/// How often the background refresh loop polls Docker for the volume list
+/// and (subject to `USAGE_CACHE_TTL`) disk usage.
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
 
 type VolumeSortState = SortState<VolumeSortField>;
 
+/// State shared between the page and its background refresh task. The task
+/// only ever holds a `Weak` handle to this, so it exits on its next poll after
+/// the page drops its strong reference (see `Close::close`).
+#[derive(Debug, Default, Clone)]
+struct VolumeSnapshot {
+    volumes: Vec<DockerVolume>,
+    usage: HashMap<String, VolumeUsageData>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ModalTypes {
     DeleteVolume,
     ForceDeleteVolume,
+    PruneVolumes,
+    ExportVolume,
 }
 
 #[derive(Debug)]
@@ -68,6 +106,13 @@ pub struct Volume {
     modal: Option<BooleanModal<ModalTypes>>,
     sort_state: VolumeSortState,
     show_dangling: bool,
+    filter_query: String,
+    is_filtering: bool,
+    usage_cache: HashMap<String, VolumeUsageData>,
+    shared: Arc<Mutex<VolumeSnapshot>>,
+    export_path: String,
+    is_exporting: bool,
+    last_export_path: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -80,7 +125,23 @@ impl Page for Volume {
             return Ok(res);
         }
 
+        if self.is_filtering {
+            return Ok(self.update_filter(message));
+        }
+
+        if self.is_exporting {
+            return self.update_export(message).await;
+        }
+
         let result = match message {
+            SLASH_KEY => {
+                self.is_filtering = true;
+                MessageResponse::Consumed
+            }
+            CTRL_E_KEY => {
+                self.start_export();
+                MessageResponse::Consumed
+            }
             UP_KEY | K_KEY => {
                 self.decrement_list();
                 MessageResponse::Consumed
@@ -117,10 +178,19 @@ impl Page for Volume {
                 self.sort_volumes();
                 MessageResponse::Consumed
             }
+            SHIFT_S_KEY => {
+                self.sort_state.toggle_or_set(VolumeSortField::Size);
+                self.sort_volumes();
+                MessageResponse::Consumed
+            }
             CTRL_D_KEY => match self.delete_volume(false, None, None) {
                 Ok(()) => MessageResponse::Consumed,
                 Err(_) => MessageResponse::NotConsumed,
             },
+            SHIFT_P_KEY => match self.prune_volumes() {
+                Ok(()) => MessageResponse::Consumed,
+                Err(_) => MessageResponse::NotConsumed,
+            },
             ALT_D_KEY => {
                 self.show_dangling = !self.show_dangling;
                 MessageResponse::Consumed
@@ -133,6 +203,14 @@ impl Page for Volume {
                     .await?;
                 MessageResponse::Consumed
             }
+            B_KEY => {
+                self.tx
+                    .send(Message::Transition(Transition::ToBrowseVolumePage(
+                        self.get_context()?,
+                    )))
+                    .await?;
+                MessageResponse::Consumed
+            }
             _ => MessageResponse::NotConsumed,
         };
         Ok(result)
@@ -142,6 +220,19 @@ impl Page for Volume {
         self.list_state = TableState::default();
         self.list_state.select(Some(0));
 
+        // Seed the shared snapshot with a synchronous list before the
+        // background task's first tick, so the page never renders an empty
+        // table (or loses the "restore previously-selected volume" lookup
+        // below) while that first poll is still in flight.
+        let volumes = DockerVolume::list(&self.docker)
+            .await
+            .context("unable to retrieve list of volumes")?;
+        self.shared = Arc::new(Mutex::new(VolumeSnapshot {
+            volumes,
+            usage: HashMap::new(),
+        }));
+
+        self.spawn_refresh_task();
         self.refresh().await.context("unable to refresh volumes")?;
 
         let volume_id: String;
@@ -169,17 +260,28 @@ impl Page for Volume {
 }
 
 #[async_trait::async_trait]
-impl Close for Volume {}
+impl Close for Volume {
+    async fn close(&mut self) -> Result<()> {
+        // Dropping our strong reference is what lets the background refresh
+        // loop's weak upgrade fail on its next tick so it can exit cleanly.
+        self.shared = Arc::new(Mutex::new(VolumeSnapshot::default()));
+        Ok(())
+    }
+}
 
 impl Volume {
     #[must_use]
     pub fn new(docker: Docker, tx: Sender<Message<Key, Transition>>, config: Arc<Config>) -> Self {
         let page_help = PageHelpBuilder::new(NAME.to_string(), config.clone())
             .add_input(format!("{CTRL_D_KEY}"), "delete".to_string())
+            .add_input(format!("{CTRL_E_KEY}"), "export".to_string())
+            .add_input(format!("{SHIFT_P_KEY}"), "prune".to_string())
             .add_input(format!("{ALT_D_KEY}"), "dangling".to_string())
             .add_input(format!("{G_KEY}"), "top".to_string())
             .add_input(format!("{SHIFT_G_KEY}"), "bottom".to_string())
             .add_input(format!("{D_KEY}"), "describe".to_string())
+            .add_input(format!("{B_KEY}"), "browse".to_string())
+            .add_input(format!("{SLASH_KEY}"), "filter".to_string())
             .build();
 
         Self {
@@ -192,20 +294,30 @@ impl Volume {
             modal: None,
             sort_state: VolumeSortState::default(),
             show_dangling: true,
+            filter_query: String::new(),
+            is_filtering: false,
+            usage_cache: HashMap::new(),
+            shared: Arc::new(Mutex::new(VolumeSnapshot::default())),
+            export_path: String::new(),
+            is_exporting: false,
+            last_export_path: None,
         }
     }
 
+    /// Reads the latest snapshot produced by the background refresh task.
+    ///
+    /// This never touches the Docker API itself, so it stays cheap enough to
+    /// run on every keystroke; the actual (possibly slow) `list`/`df` calls
+    /// happen independently in the task spawned by `spawn_refresh_task`.
     async fn refresh(&mut self) -> Result<(), color_eyre::eyre::Error> {
-        let mut filters: HashMap<String, Vec<String>> = HashMap::new();
-        if self.show_dangling {
-            filters.insert("dangling".into(), vec!["true".into()]);
-        } else {
-            filters.insert("dangling".into(), vec!["false".into()]);
-        }
+        let snapshot = self
+            .shared
+            .lock()
+            .expect("volume snapshot lock poisoned")
+            .clone();
 
-        self.volumes = DockerVolume::list(&self.docker)
-            .await
-            .context("unable to retrieve list of volumes")?;
+        self.volumes = snapshot.volumes;
+        self.usage_cache = snapshot.usage;
 
         // Apply current sort after refresh
         self.sort_volumes();
@@ -213,27 +325,161 @@ impl Volume {
         Ok(())
     }
 
+    /// Bypasses the background task's poll cadence to list volumes right
+    /// now, for callers (e.g. after a delete/prune/export completes) that
+    /// can't wait up to `POLL_INTERVAL` for the list to catch up.
+    async fn force_refresh(&mut self) -> Result<()> {
+        let volumes = DockerVolume::list(&self.docker)
+            .await
+            .context("unable to retrieve list of volumes")?;
+
+        self.shared
+            .lock()
+            .expect("volume snapshot lock poisoned")
+            .volumes = volumes;
+
+        self.refresh().await
+    }
+
+    /// Spawns the background task that keeps `self.shared` up to date by
+    /// periodically listing volumes and (on a slower cadence) pulling disk
+    /// usage via `docker system df`.
+    ///
+    /// The task holds only a `Weak` reference to the shared state, so it
+    /// exits cleanly on its next poll after the page drops its strong
+    /// reference (see `Close::close`) rather than leaking or racing with a
+    /// fresh one spawned by a later `initialise`.
+    fn spawn_refresh_task(&mut self) {
+        let shared = Arc::downgrade(&self.shared);
+        let docker = self.docker.clone();
+
+        tokio::spawn(async move {
+            let mut usage_last_fetched: Option<Instant> = None;
+
+            loop {
+                let Some(shared) = shared.upgrade() else {
+                    break;
+                };
+
+                if let Ok(volumes) = DockerVolume::list(&docker).await {
+                    shared.lock().expect("volume snapshot lock poisoned").volumes = volumes;
+                }
+
+                if usage_last_fetched.is_none_or(|t| t.elapsed() >= USAGE_CACHE_TTL) {
+                    if let Ok(df) = docker.df().await {
+                        let usage = df
+                            .volumes
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter_map(|v| v.usage_data.clone().map(|u| (v.name.clone(), u)))
+                            .collect();
+                        shared.lock().expect("volume snapshot lock poisoned").usage = usage;
+                        usage_last_fetched = Some(Instant::now());
+                    }
+                }
+
+                drop(shared);
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        });
+    }
+
     fn sort_volumes(&mut self) {
+        // Keyed off `filter_query` rather than `is_filtering` so a confirmed
+        // filter (Enter exits edit mode but keeps the query) stays applied
+        // instead of being silently discarded by the next `refresh()`.
+        if !self.filter_query.is_empty() {
+            self.filter_and_score_volumes();
+        } else {
+            let field = self.sort_state.field;
+            let order = self.sort_state.order;
+            let usage_cache = &self.usage_cache;
+            self.volumes
+                .sort_by(|a, b| Self::compare_volumes(a, b, field, order, usage_cache));
+        }
+
+        if self.list_state.selected().is_some_and(|i| i >= self.volumes.len()) {
+            self.list_state
+                .select((!self.volumes.is_empty()).then_some(0));
+        }
+    }
+
+    /// Filters `self.volumes` down to those matching `self.filter_query` and
+    /// sorts them by descending fuzzy score, breaking ties with the current
+    /// sort field.
+    fn filter_and_score_volumes(&mut self) {
         let field = self.sort_state.field;
         let order = self.sort_state.order;
+        let usage_cache = &self.usage_cache;
+
+        let mut scored: Vec<(DockerVolume, i64)> = self
+            .volumes
+            .drain(..)
+            .filter_map(|v| fuzzy_score(&self.filter_query, &v.name).map(|score| (v, score)))
+            .collect();
+
+        scored.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| Self::compare_volumes(a, b, field, order, usage_cache))
+        });
 
-        self.volumes.sort_by(|a, b| {
-            let comparison = match field {
-                VolumeSortField::Name => a.name.cmp(&b.name),
-                VolumeSortField::Driver => a.driver.cmp(&b.driver),
-                VolumeSortField::Mountpoint => a.mountpoint.cmp(&b.mountpoint),
-                VolumeSortField::Created => {
-                    let a_created = a.created_at.as_deref().unwrap_or("");
-                    let b_created = b.created_at.as_deref().unwrap_or("");
-                    a_created.cmp(b_created)
-                }
-            };
+        self.volumes = scored.into_iter().map(|(v, _)| v).collect();
+        self.list_state
+            .select((!self.volumes.is_empty()).then_some(0));
+    }
 
-            match order {
-                SortOrder::Ascending => comparison,
-                SortOrder::Descending => comparison.reverse(),
+    fn compare_volumes(
+        a: &DockerVolume,
+        b: &DockerVolume,
+        field: VolumeSortField,
+        order: SortOrder,
+        usage_cache: &HashMap<String, VolumeUsageData>,
+    ) -> std::cmp::Ordering {
+        let comparison = match field {
+            VolumeSortField::Name => a.name.cmp(&b.name),
+            VolumeSortField::Driver => a.driver.cmp(&b.driver),
+            VolumeSortField::Mountpoint => a.mountpoint.cmp(&b.mountpoint),
+            VolumeSortField::Created => {
+                let a_created = a.created_at.as_deref().unwrap_or("");
+                let b_created = b.created_at.as_deref().unwrap_or("");
+                a_created.cmp(b_created)
             }
-        });
+            VolumeSortField::Size => {
+                let a_size = usage_cache.get(&a.name).map_or(0, |u| u.size);
+                let b_size = usage_cache.get(&b.name).map_or(0, |u| u.size);
+                a_size.cmp(&b_size)
+            }
+        };
+
+        match order {
+            SortOrder::Ascending => comparison,
+            SortOrder::Descending => comparison.reverse(),
+        }
+    }
+
+    fn update_filter(&mut self, message: Key) -> MessageResponse {
+        match message {
+            FILTER_EXIT_KEY => {
+                self.is_filtering = false;
+                self.filter_query.clear();
+                self.sort_volumes();
+            }
+            FILTER_CONFIRM_KEY => {
+                self.is_filtering = false;
+            }
+            FILTER_BACKSPACE_KEY => {
+                self.filter_query.pop();
+                self.sort_volumes();
+            }
+            Key::Char(c) => {
+                self.filter_query.push(c);
+                self.sort_volumes();
+            }
+            _ => return MessageResponse::NotConsumed,
+        }
+
+        MessageResponse::Consumed
     }
 
     async fn update_modal(&mut self, message: Key) -> Result<MessageResponse> {
@@ -249,21 +495,38 @@ impl Volume {
             match m.update(message).await {
                 Ok(_) => {
                     if let ModalState::Closed = m.state {
+                        let discriminator = m.discriminator;
                         self.modal = None;
+
+                        // A destructive action just ran: poll immediately
+                        // instead of leaving the stale list up to
+                        // `POLL_INTERVAL` behind.
+                        if matches!(
+                            discriminator,
+                            ModalTypes::DeleteVolume
+                                | ModalTypes::ForceDeleteVolume
+                                | ModalTypes::PruneVolumes
+                                | ModalTypes::ExportVolume
+                        ) {
+                            self.force_refresh().await?;
+                        }
                     }
                 }
-                Err(e) => {
-                    if let ModalTypes::DeleteVolume = m.discriminator {
+                Err(e) => match m.discriminator {
+                    ModalTypes::DeleteVolume => {
                         let msg = "An error occurred deleting this volume; would you like to try to force remove?";
                         self.delete_volume(
                             true,
                             Some(msg.into()),
                             Some(ModalTypes::ForceDeleteVolume),
                         )?;
-                    } else {
-                        return Err(e);
                     }
-                }
+                    ModalTypes::ExportVolume => {
+                        let msg = "An error occurred exporting this volume; would you like to retry?";
+                        self.export_volume(Some(msg.into()))?;
+                    }
+                    _ => return Err(e),
+                },
             }
             Ok(MessageResponse::Consumed)
         } else {
@@ -358,20 +621,143 @@ impl Volume {
         }
         Ok(())
     }
+
+    /// Opens a confirmation modal summarising how many volumes would be
+    /// reclaimed by `docker volume prune` and how much space they hold,
+    /// derived from the same usage data backing the Size column.
+    fn prune_volumes(&mut self) -> Result<()> {
+        let (count, reclaimable) = self.unused_volume_stats();
+
+        let cb = Arc::new(FutureMutex::new(PruneVolumes::new(self.docker.clone())));
+
+        let mut modal = BooleanModal::<ModalTypes>::new("Prune".into(), ModalTypes::PruneVolumes);
+
+        modal.initialise(
+            format!(
+                "Prune {count} unused volume{} and reclaim {}? This cannot be undone.",
+                if count == 1 { "" } else { "s" },
+                human_bytes(reclaimable),
+            ),
+            Some(cb),
+        );
+        self.modal = Some(modal);
+
+        Ok(())
+    }
+
+    fn unused_volume_stats(&self) -> (usize, i64) {
+        // `self.volumes` may be narrowed by the filter, which prune ignores,
+        // so count over the unfiltered snapshot.
+        let snapshot = self.shared.lock().expect("volume snapshot lock poisoned");
+        let usages = snapshot
+            .volumes
+            .iter()
+            .filter_map(|v| self.usage_cache.get(&v.name));
+        summarize_unused(usages)
+    }
+
+    /// Opens the destination-path input for exporting the selected volume.
+    fn start_export(&mut self) {
+        if let Ok(volume) = self.get_volume() {
+            self.export_path = format!("./{}.tar", volume.name);
+            self.is_exporting = true;
+        }
+    }
+
+    async fn update_export(&mut self, message: Key) -> Result<MessageResponse> {
+        let result = match message {
+            EXPORT_EXIT_KEY => {
+                self.is_exporting = false;
+                self.export_path.clear();
+                MessageResponse::Consumed
+            }
+            EXPORT_CONFIRM_KEY => {
+                self.is_exporting = false;
+                let message_override = None;
+                self.export_volume(message_override)?;
+                MessageResponse::Consumed
+            }
+            EXPORT_BACKSPACE_KEY => {
+                self.export_path.pop();
+                MessageResponse::Consumed
+            }
+            Key::Char(c) => {
+                self.export_path.push(c);
+                MessageResponse::Consumed
+            }
+            _ => MessageResponse::NotConsumed,
+        };
+
+        Ok(result)
+    }
+
+    /// Opens a confirmation modal to export the selected volume to
+    /// `self.export_path` (or `self.last_export_path` on a retry), streaming
+    /// its contents into a `.tar` archive via a helper container.
+    fn export_volume(&mut self, message_override: Option<String>) -> Result<()> {
+        if let Ok(volume) = self.get_volume() {
+            let name = volume.name.clone();
+            let path = match message_override {
+                Some(_) => self
+                    .last_export_path
+                    .clone()
+                    .context("no previous export destination to retry")?,
+                None => self.export_path.clone(),
+            };
+            self.last_export_path = Some(path.clone());
+
+            let cb = Arc::new(FutureMutex::new(ExportVolume::new(
+                self.docker.clone(),
+                volume.clone(),
+                path.clone(),
+            )));
+
+            let mut modal =
+                BooleanModal::<ModalTypes>::new("Export".into(), ModalTypes::ExportVolume);
+
+            modal.initialise(
+                if let Some(m) = message_override {
+                    m
+                } else {
+                    format!("Export volume {name} to {path}?")
+                },
+                Some(cb),
+            );
+            self.modal = Some(modal);
+        } else {
+            bail!("Ahhh")
+        }
+        Ok(())
+    }
 }
 
 impl Component for Volume {
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) {
-        let rows = get_volume_rows(&self.volumes);
+        let table_area = if self.is_filtering {
+            let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+            f.render_widget(Line::from(format!("/{}", self.filter_query)), chunks[0]);
+            chunks[1]
+        } else if self.is_exporting {
+            let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+            f.render_widget(
+                Line::from(format!("Export to: {}", self.export_path)),
+                chunks[0],
+            );
+            chunks[1]
+        } else {
+            area
+        };
+
+        let rows = get_volume_rows(&self.volumes, &self.usage_cache);
         let columns = get_header_row(&self.sort_state);
 
-        let widths = constraints![==30%, ==15%, ==30%, ==25%];
+        let widths = constraints![==25%, ==12%, ==26%, ==20%, ==17%];
 
         let table = Table::new(rows.clone(), widths)
             .header(columns.clone().style(Style::new().bold()))
             .row_highlight_style(Style::new().reversed());
 
-        f.render_stateful_widget(table, area, &mut self.list_state);
+        f.render_stateful_widget(table, table_area, &mut self.list_state);
 
         if let Some(m) = self.modal.as_mut() {
             if let ModalState::Open(_) = m.state {
@@ -381,21 +767,56 @@ impl Component for Volume {
     }
 }
 
-fn get_volume_rows(volumes: &[DockerVolume]) -> Vec<Row> {
+fn get_volume_rows(volumes: &[DockerVolume], usage_cache: &HashMap<String, VolumeUsageData>) -> Vec<Row> {
     let rows = volumes
         .iter()
         .map(|c| {
+            let size = usage_cache
+                .get(&c.name)
+                .map_or_else(|| "-".to_string(), |u| human_bytes(u.size));
+
             Row::new(vec![
                 c.name.clone(),
                 c.driver.clone(),
                 c.mountpoint.clone(),
                 c.created_at.clone().unwrap_or_default(),
+                size,
             ])
         })
         .collect::<Vec<Row>>();
     rows
 }
 
+/// Counts and sums the reclaimable size of volumes with no container
+/// references (`ref_count == 0`), for the prune confirmation summary.
+fn summarize_unused<'a>(usages: impl Iterator<Item = &'a VolumeUsageData>) -> (usize, i64) {
+    usages
+        .filter(|u| u.ref_count == 0)
+        .fold((0, 0), |(count, size), u| (count + 1, size + u.size))
+}
+
+/// Formats a byte count as a short human-readable string, e.g. `1.5 MB`.
+fn human_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    if bytes <= 0 {
+        return "0 B".to_string();
+    }
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
 fn get_header_row(sort_state: &VolumeSortState) -> Row {
     let headers = vec![
         render_column_header(
@@ -422,7 +843,65 @@ fn get_header_row(sort_state: &VolumeSortState) -> Row {
             get_field_sort_order(sort_state, &VolumeSortField::Created)
                 .unwrap_or(SortOrder::Ascending),
         ),
+        render_column_header(
+            "Size",
+            is_field_sorted(sort_state, &VolumeSortField::Size),
+            get_field_sort_order(sort_state, &VolumeSortField::Size)
+                .unwrap_or(SortOrder::Ascending),
+        ),
     ];
 
     Row::new(headers)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_positive_byte_counts_are_zero() {
+        assert_eq!(human_bytes(0), "0 B");
+        assert_eq!(human_bytes(-1), "0 B");
+    }
+
+    #[test]
+    fn sub_kilobyte_counts_stay_in_bytes() {
+        assert_eq!(human_bytes(1), "1 B");
+        assert_eq!(human_bytes(1023), "1023 B");
+    }
+
+    #[test]
+    fn exact_unit_boundaries_roll_over() {
+        assert_eq!(human_bytes(1024), "1.0 KB");
+        assert_eq!(human_bytes(1024 * 1024), "1.0 MB");
+        assert_eq!(human_bytes(1024 * 1024 * 1024), "1.0 GB");
+    }
+
+    #[test]
+    fn huge_counts_cap_at_terabytes() {
+        let far_beyond_tb = 1024_i64.pow(5);
+        assert_eq!(human_bytes(far_beyond_tb), "1024.0 TB");
+    }
+
+    fn usage(size: i64, ref_count: i64) -> VolumeUsageData {
+        VolumeUsageData { size, ref_count }
+    }
+
+    #[test]
+    fn summarize_unused_ignores_referenced_volumes() {
+        let usages = vec![usage(100, 1), usage(200, 2)];
+        assert_eq!(summarize_unused(usages.iter()), (0, 0));
+    }
+
+    #[test]
+    fn summarize_unused_counts_and_sums_unreferenced_volumes() {
+        let usages = vec![usage(100, 0), usage(200, 1), usage(300, 0)];
+        assert_eq!(summarize_unused(usages.iter()), (2, 400));
+    }
+
+    #[test]
+    fn summarize_unused_of_empty_input_is_zero() {
+        let usages: Vec<VolumeUsageData> = vec![];
+        assert_eq!(summarize_unused(usages.iter()), (0, 0));
+    }
+}